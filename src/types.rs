@@ -7,12 +7,30 @@ use rand::Rng;
 use rand::distributions::*;
 use std::fmt::Debug;
 use std::iter::Sum;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 
 use super::lapack_traits::LapackScalar;
 
 pub use num_complex::Complex32 as c32;
 pub use num_complex::Complex64 as c64;
 
+/// Marker for the `serde` (de)serialization bound on [Scalar](trait.Scalar.html).
+///
+/// When the `serde` feature is on this is `Serialize + for<'de> Deserialize<'de>`;
+/// otherwise it is an empty bound, so builds without `serde` still work. It is blanket
+/// implemented, so callers never name it directly.
+#[cfg(feature = "serde")]
+pub trait Serializable: Serialize + for<'de> Deserialize<'de> {}
+#[cfg(feature = "serde")]
+impl<T: Serialize + for<'de> Deserialize<'de>> Serializable for T {}
+
+/// Marker for the `serde` (de)serialization bound on [Scalar](trait.Scalar.html).
+#[cfg(not(feature = "serde"))]
+pub trait Serializable {}
+#[cfg(not(feature = "serde"))]
+impl<T> Serializable for T {}
+
 /// General Scalar trait. This generalizes complex and real number.
 ///
 /// You can use the following operations with `A: Scalar`:
@@ -20,9 +38,20 @@ pub use num_complex::Complex64 as c64;
 /// - [abs](trait.Absolute.html#method.abs)
 /// - [squared](trait.Absolute.html#tymethod.squared)
 /// - [sqrt](trait.SquareRoot.html#tymethod.sqrt)
-/// - [exp](trait.Exponential.html#tymethod.exp)
+/// - [exp](trait.Exponential.html#tymethod.exp) and the other transcendentals
+///   ([ln](trait.Exponential.html#tymethod.ln), `sin`/`cos`/`tan`, `asin`/`acos`/`atan`,
+///   `sinh`/`cosh`/`tanh`)
+/// - [powi](trait.Power.html#tymethod.powi)/[powf](trait.Power.html#tymethod.powf)/
+///   [pow](trait.Power.html#tymethod.pow)/[powc](trait.Power.html#tymethod.powc)
+/// - [real](trait.Constructor.html#tymethod.real)/
+///   [complex](trait.Constructor.html#tymethod.complex)/
+///   [from_real](trait.Constructor.html#tymethod.from_real)
 /// - [conj](trait.Conjugate.html#tymethod.conj)
-/// - [randn](trait.RandNormal.html#tymethod.randn)
+/// - [randn](trait.RandNormal.html#tymethod.randn)/
+///   [randn_with](trait.RandNormal.html#tymethod.randn_with)
+///
+/// The `floor`/`ceil`/`round`/`trunc`/`fract` operations of
+/// [Round](trait.Round.html) are additionally available on `A: RealScalar`.
 ///
 pub trait Scalar
     : LapackScalar
@@ -32,8 +61,11 @@ pub trait Scalar
     + Absolute
     + SquareRoot
     + Exponential
+    + Power
+    + Constructor
     + Conjugate
     + RandNormal
+    + Serializable
     + Debug {
 }
 
@@ -42,7 +74,7 @@ impl Scalar for f64 {}
 impl Scalar for c32 {}
 impl Scalar for c64 {}
 
-pub trait RealScalar: Scalar + Float + Sum {
+pub trait RealScalar: Scalar + Float + Sum + Round {
     fn from_f64(f64) -> Self;
 }
 
@@ -100,9 +132,58 @@ pub trait SquareRoot {
     fn sqrt(&self) -> Self;
 }
 
-/// Define `exp()` more generally
+/// Define elementary transcendental functions more generally
 pub trait Exponential {
     fn exp(&self) -> Self;
+    fn ln(&self) -> Self;
+    fn sin(&self) -> Self;
+    fn cos(&self) -> Self;
+    fn tan(&self) -> Self;
+    fn asin(&self) -> Self;
+    fn acos(&self) -> Self;
+    fn atan(&self) -> Self;
+    fn sinh(&self) -> Self;
+    fn cosh(&self) -> Self;
+    fn tanh(&self) -> Self;
+}
+
+/// Define generic exponentiation to integer, real, same-type, and complex powers
+pub trait Power: AssociatedReal + AssociatedComplex {
+    fn powi(self, n: i32) -> Self;
+    fn powf(self, n: Self::Real) -> Self;
+    fn pow(self, n: Self) -> Self;
+    fn powc(self, n: Self::Complex) -> Self::Complex;
+}
+
+/// Construct scalars from arbitrary primitives
+///
+/// Generalizes [into_real](fn.into_real.html), which only accepts `f64`.
+pub trait Constructor: AssociatedReal + AssociatedComplex {
+    /// Build the real part type from any primitive.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `re` cannot be represented as `Self::Real`, i.e. `NumCast::from(re)`
+    /// returns `None` (for an `f32` real type this is decided by `re.to_f32()`).
+    fn real<T: ToPrimitive>(re: T) -> Self::Real;
+    /// Build the complex type from primitive real and imaginary parts.
+    ///
+    /// # Panics
+    ///
+    /// Panics under the same condition as [real](#tymethod.real).
+    fn complex<T: ToPrimitive>(re: T, im: T) -> Self::Complex;
+    fn from_real(re: Self::Real) -> Self;
+}
+
+/// Element-level rounding operations
+///
+/// For complex types each method acts on the real and imaginary parts independently.
+pub trait Round {
+    fn floor(&self) -> Self;
+    fn ceil(&self) -> Self;
+    fn round(&self) -> Self;
+    fn trunc(&self) -> Self;
+    fn fract(&self) -> Self;
 }
 
 /// Complex conjugate value
@@ -111,8 +192,19 @@ pub trait Conjugate: Copy {
 }
 
 /// Scalars which can be initialized from Gaussian random number
-pub trait RandNormal {
+pub trait RandNormal: AssociatedReal + AssociatedComplex {
     fn randn<R: Rng>(&mut R) -> Self;
+    /// Sample from a parameterized Gaussian of the given mean and standard deviation
+    ///
+    /// For complex types the real and imaginary parts are each drawn from this Gaussian,
+    /// so the result is centred on `mean + mean * i`.
+    fn randn_with<R: Rng>(&mut R, mean: Self::Real, std: Self::Real) -> Self;
+    /// Sample a complex value by drawing the real and imaginary parts from independent distributions
+    fn sample_complex<R, Dre, Dim>(&mut R, &Dre, &Dim) -> Self::Complex
+    where
+        R: Rng,
+        Dre: IndependentSample<Self::Real>,
+        Dim: IndependentSample<Self::Real>;
 }
 
 macro_rules! impl_traits {
@@ -186,12 +278,162 @@ impl Exponential for $real {
     fn exp(&self) -> Self {
         Float::exp(*self)
     }
+    fn ln(&self) -> Self {
+        Float::ln(*self)
+    }
+    fn sin(&self) -> Self {
+        Float::sin(*self)
+    }
+    fn cos(&self) -> Self {
+        Float::cos(*self)
+    }
+    fn tan(&self) -> Self {
+        Float::tan(*self)
+    }
+    fn asin(&self) -> Self {
+        Float::asin(*self)
+    }
+    fn acos(&self) -> Self {
+        Float::acos(*self)
+    }
+    fn atan(&self) -> Self {
+        Float::atan(*self)
+    }
+    fn sinh(&self) -> Self {
+        Float::sinh(*self)
+    }
+    fn cosh(&self) -> Self {
+        Float::cosh(*self)
+    }
+    fn tanh(&self) -> Self {
+        Float::tanh(*self)
+    }
 }
 
 impl Exponential for $complex {
     fn exp(&self) -> Self {
         Complex::exp(self)
     }
+    fn ln(&self) -> Self {
+        Complex::ln(self)
+    }
+    fn sin(&self) -> Self {
+        Complex::sin(self)
+    }
+    fn cos(&self) -> Self {
+        Complex::cos(self)
+    }
+    fn tan(&self) -> Self {
+        Complex::tan(self)
+    }
+    fn asin(&self) -> Self {
+        Complex::asin(self)
+    }
+    fn acos(&self) -> Self {
+        Complex::acos(self)
+    }
+    fn atan(&self) -> Self {
+        Complex::atan(self)
+    }
+    fn sinh(&self) -> Self {
+        Complex::sinh(self)
+    }
+    fn cosh(&self) -> Self {
+        Complex::cosh(self)
+    }
+    fn tanh(&self) -> Self {
+        Complex::tanh(self)
+    }
+}
+
+impl Power for $real {
+    fn powi(self, n: i32) -> Self {
+        Float::powi(self, n)
+    }
+    fn powf(self, n: Self::Real) -> Self {
+        Float::powf(self, n)
+    }
+    fn pow(self, n: Self) -> Self {
+        Float::powf(self, n)
+    }
+    fn powc(self, n: Self::Complex) -> Self::Complex {
+        Complex::powc(&AssociatedComplex::inject(self), n)
+    }
+}
+
+impl Power for $complex {
+    fn powi(self, n: i32) -> Self {
+        Complex::powc(&self, Self::new(n as $real, 0.0))
+    }
+    fn powf(self, n: Self::Real) -> Self {
+        Complex::powf(&self, n)
+    }
+    fn pow(self, n: Self) -> Self {
+        Complex::powc(&self, n)
+    }
+    fn powc(self, n: Self::Complex) -> Self::Complex {
+        Complex::powc(&self, n)
+    }
+}
+
+impl Constructor for $real {
+    fn real<T: ToPrimitive>(re: T) -> Self::Real {
+        NumCast::from(re).unwrap()
+    }
+    fn complex<T: ToPrimitive>(re: T, im: T) -> Self::Complex {
+        Self::Complex::new(Self::real(re), Self::real(im))
+    }
+    fn from_real(re: Self::Real) -> Self {
+        AssociatedReal::inject(re)
+    }
+}
+
+impl Constructor for $complex {
+    fn real<T: ToPrimitive>(re: T) -> Self::Real {
+        NumCast::from(re).unwrap()
+    }
+    fn complex<T: ToPrimitive>(re: T, im: T) -> Self::Complex {
+        Self::new(<$real as Constructor>::real(re), <$real as Constructor>::real(im))
+    }
+    fn from_real(re: Self::Real) -> Self {
+        AssociatedReal::inject(re)
+    }
+}
+
+impl Round for $real {
+    fn floor(&self) -> Self {
+        Float::floor(*self)
+    }
+    fn ceil(&self) -> Self {
+        Float::ceil(*self)
+    }
+    fn round(&self) -> Self {
+        Float::round(*self)
+    }
+    fn trunc(&self) -> Self {
+        Float::trunc(*self)
+    }
+    fn fract(&self) -> Self {
+        Float::fract(*self)
+    }
+}
+
+impl Round for $complex {
+    fn floor(&self) -> Self {
+        Self::new(Float::floor(self.re), Float::floor(self.im))
+    }
+    fn ceil(&self) -> Self {
+        Self::new(Float::ceil(self.re), Float::ceil(self.im))
+    }
+    fn round(&self) -> Self {
+        Self::new(Float::round(self.re), Float::round(self.im))
+    }
+    fn trunc(&self) -> Self {
+        Self::new(Float::trunc(self.re), Float::trunc(self.im))
+    }
+    fn fract(&self) -> Self {
+        Self::new(Float::fract(self.re), Float::fract(self.im))
+    }
 }
 
 impl Conjugate for $real {
@@ -208,21 +450,97 @@ impl Conjugate for $complex {
 
 impl RandNormal for $real {
     fn randn<R: Rng>(rng: &mut R) -> Self {
-        let dist = Normal::new(0., 1.);
+        Self::randn_with(rng, 0., 1.)
+    }
+    fn randn_with<R: Rng>(rng: &mut R, mean: Self::Real, std: Self::Real) -> Self {
+        let dist = Normal::new(mean as f64, std as f64);
         dist.ind_sample(rng) as $real
     }
+    fn sample_complex<R, Dre, Dim>(rng: &mut R, re: &Dre, im: &Dim) -> Self::Complex
+    where
+        R: Rng,
+        Dre: IndependentSample<Self::Real>,
+        Dim: IndependentSample<Self::Real>,
+    {
+        Self::Complex::new(re.ind_sample(rng), im.ind_sample(rng))
+    }
 }
 
 impl RandNormal for $complex {
     fn randn<R: Rng>(rng: &mut R) -> Self {
-        let dist = Normal::new(0., 1.);
+        Self::randn_with(rng, 0., 1.)
+    }
+    fn randn_with<R: Rng>(rng: &mut R, mean: Self::Real, std: Self::Real) -> Self {
+        let dist = Normal::new(mean as f64, std as f64);
         let re = dist.ind_sample(rng) as $real;
         let im = dist.ind_sample(rng) as $real;
         Self::new(re, im)
     }
+    fn sample_complex<R, Dre, Dim>(rng: &mut R, re: &Dre, im: &Dim) -> Self::Complex
+    where
+        R: Rng,
+        Dre: IndependentSample<Self::Real>,
+        Dim: IndependentSample<Self::Real>,
+    {
+        Self::new(re.ind_sample(rng), im.ind_sample(rng))
+    }
 }
 
 }} // impl_traits!
 
 impl_traits!(f64, c64);
 impl_traits!(f32, c32);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn transcendental() {
+        let x = 0.5_f64;
+        assert!((Exponential::ln(&Exponential::exp(&x)) - x).abs() < 1e-12);
+        assert!(Exponential::sin(&0.0_f64).abs() < 1e-12);
+        assert!((Exponential::cos(&0.0_f64) - 1.0).abs() < 1e-12);
+        let z = c64::new(0.3, -0.4);
+        assert!((Exponential::ln(&Exponential::exp(&z)) - z).norm() < 1e-12);
+    }
+
+    #[test]
+    fn power() {
+        assert!((Power::powi(2.0_f64, 3) - 8.0).abs() < 1e-12);
+        assert!((Power::powf(4.0_f64, 0.5) - 2.0).abs() < 1e-12);
+        // real `powc` injects into the complex type before exponentiating
+        let p = Power::powc(2.0_f64, c64::new(2.0, 0.0));
+        assert!((p - c64::new(4.0, 0.0)).norm() < 1e-12);
+        assert!((Power::powi(c64::new(0.0, 1.0), 2) - c64::new(-1.0, 0.0)).norm() < 1e-12);
+    }
+
+    #[test]
+    fn construct() {
+        assert_eq!(<f64 as Constructor>::real(3_i32), 3.0);
+        assert_eq!(<f64 as Constructor>::real(2_usize), 2.0);
+        assert_eq!(<c64 as Constructor>::complex(1_i32, 2_i32), c64::new(1.0, 2.0));
+        assert_eq!(<c64 as Constructor>::from_real(5.0), c64::new(5.0, 0.0));
+    }
+
+    #[test]
+    fn randn_parameterized() {
+        let mut rng = ::rand::thread_rng();
+        // zero standard deviation collapses the Gaussian onto its mean
+        let x = <f64 as RandNormal>::randn_with(&mut rng, 3.0, 0.0);
+        assert!((x - 3.0).abs() < 1e-12);
+        let z = <c64 as RandNormal>::randn_with(&mut rng, -1.0, 0.0);
+        assert!((z - c64::new(-1.0, -1.0)).norm() < 1e-12);
+    }
+
+    #[test]
+    fn rounding() {
+        assert_eq!(Round::floor(&1.7_f64), 1.0);
+        assert_eq!(Round::ceil(&1.2_f64), 2.0);
+        assert_eq!(Round::round(&1.5_f64), 2.0);
+        assert_eq!(Round::trunc(&(-1.7_f64)), -1.0);
+        assert!((Round::fract(&1.25_f64) - 0.25).abs() < 1e-12);
+        // complex rounds each component independently
+        assert_eq!(Round::floor(&c64::new(1.7, -0.3)), c64::new(1.0, -1.0));
+    }
+}